@@ -0,0 +1,444 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use bracket_lib::prelude::*;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use serde::{Deserialize, Serialize};
+
+use crate::obstacle::Obstacle;
+use crate::player::{Player, PhysicsConfig};
+use crate::{ASSETS_DIR, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+pub enum GameMode {
+    Menu,
+    Playing,
+    Paused,
+    End,
+}
+
+// Loads the game's clips once (as raw bytes, so each play can decode its own
+// independent stream) and plays them on demand via rodio, with a mute toggle
+// so headless/CI runs can disable audio entirely. bracket-lib/bracket-terminal
+// has no audio support of its own, so clips are played through rodio directly.
+// The output stream is acquired best-effort: machines with no audio device
+// (exactly the headless/CI case the mute toggle exists for) must still reach
+// the menu, so a missing device auto-mutes instead of panicking.
+struct Audio {
+    stream: Option<(OutputStream, OutputStreamHandle)>, // None when no output device was found
+    flap: Vec<u8>,
+    point: Vec<u8>,
+    crash: Vec<u8>,
+    muted: bool,
+}
+
+impl Audio {
+    fn new(assets_dir: &str) -> Self {
+        let stream = OutputStream::try_default().ok();
+        let muted = stream.is_none();
+        Self {
+            stream,
+            flap: fs::read(format!("{}/flap.wav", assets_dir)).unwrap_or_default(),
+            point: fs::read(format!("{}/point.wav", assets_dir)).unwrap_or_default(),
+            crash: fs::read(format!("{}/crash.wav", assets_dir)).unwrap_or_default(),
+            muted,
+        }
+    }
+
+    fn play_clip(&self, clip: &[u8]) {
+        if self.muted || clip.is_empty() {
+            return;
+        }
+
+        let Some((_, handle)) = &self.stream else {
+            return;
+        };
+
+        if let Ok(source) = Decoder::new(Cursor::new(clip.to_vec())) {
+            if let Ok(sink) = Sink::try_new(handle) {
+                sink.append(source);
+                sink.detach(); // let it finish playing without blocking the game loop
+            }
+        }
+    }
+
+    fn play_flap(&self) {
+        self.play_clip(&self.flap);
+    }
+
+    fn play_point(&self) {
+        self.play_clip(&self.point);
+    }
+
+    fn play_crash(&self) {
+        self.play_clip(&self.crash);
+    }
+
+    fn toggle_mute(&mut self) {
+        self.muted = !self.muted;
+    }
+}
+
+const HIGH_SCORE_COUNT: usize = 5;
+const HIGH_SCORE_FILE: &str = "flappy-dragon-scores.json";
+
+// The top `HIGH_SCORE_COUNT` scores, persisted as JSON in the user's data
+// dir so progression carries over between sessions.
+#[derive(Serialize, Deserialize, Default)]
+struct HighScores {
+    scores: Vec<i32>,
+}
+
+impl HighScores {
+    fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(contents) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    fn path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_default()
+            .join("flappy-dragon")
+            .join(HIGH_SCORE_FILE)
+    }
+
+    // Inserts `score` if it ranks in the top `HIGH_SCORE_COUNT`, keeping the
+    // list sorted highest-first, and reports whether it qualified.
+    fn try_insert(&mut self, score: i32) -> bool {
+        let qualifies = self.scores.len() < HIGH_SCORE_COUNT
+            || self.scores.last().is_none_or(|&lowest| score > lowest);
+
+        if qualifies {
+            self.scores.push(score);
+            self.scores.sort_unstable_by(|a, b| b.cmp(a));
+            self.scores.truncate(HIGH_SCORE_COUNT);
+        }
+
+        qualifies
+    }
+
+    fn best(&self) -> i32 {
+        self.scores.first().copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod high_scores_tests {
+    use super::HighScores;
+
+    #[test]
+    fn inserts_into_empty_table() {
+        let mut scores = HighScores::default();
+        assert!(scores.try_insert(10));
+        assert_eq!(scores.scores, vec![10]);
+        assert_eq!(scores.best(), 10);
+    }
+
+    #[test]
+    fn keeps_scores_sorted_highest_first() {
+        let mut scores = HighScores::default();
+        for score in [3, 7, 1, 9, 5] {
+            scores.try_insert(score);
+        }
+        assert_eq!(scores.scores, vec![9, 7, 5, 3, 1]);
+        assert_eq!(scores.best(), 9);
+    }
+
+    #[test]
+    fn truncates_to_high_score_count_and_rejects_ties_below_the_cutoff() {
+        let mut scores = HighScores::default();
+        for score in [5, 4, 3, 2, 1] {
+            assert!(scores.try_insert(score));
+        }
+
+        // table is full at the cutoff: a tie with the lowest entry doesn't qualify
+        assert!(!scores.try_insert(1));
+        assert_eq!(scores.scores.len(), 5);
+
+        // but anything strictly higher than the current lowest bumps it out
+        assert!(scores.try_insert(2));
+        assert_eq!(scores.scores, vec![5, 4, 3, 2, 2]);
+    }
+}
+
+// Games State
+pub struct State {
+    mode: GameMode,  // store current game mode
+    player: Player,      // players instance object
+    physics: PhysicsConfig, // tunable gravity/terminal velocity/flap strength
+    score: i32, // players current score
+    obstacles: VecDeque<Obstacle>, // walls currently on screen, ordered nearest-first
+    audio: Audio, // flap/point/crash sound effects
+    high_scores: HighScores, // persisted top scores, loaded once at startup
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self {
+            mode: GameMode::Menu,  // initial State
+            player: Player::new(5, 25),
+            physics: PhysicsConfig::default(),
+            obstacles: VecDeque::from([Obstacle::new(SCREEN_WIDTH, 0)]),
+            score: 0,
+            audio: Audio::new(ASSETS_DIR),
+            high_scores: HighScores::load(),
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl State {
+    fn restart(&mut self){ // reset to initial state
+        self.player = Player::new(5, 25);
+        self.mode = GameMode::Playing;
+        self.obstacles = VecDeque::from([Obstacle::new(SCREEN_WIDTH, 0)]);
+        self.score = 0;
+    }
+
+    fn main_menu(&mut self, ctx: &mut BTerm){
+        // clear screen
+        ctx.cls();
+
+        // Print Menu Options
+        ctx.print_centered(5, "Welcome to Flappy Dragon");
+        ctx.print_centered(8, "(P) Play Game");
+        ctx.print_centered(9, "(Q) Quit Game");
+        ctx.print_centered(11, format!("(M) Sound: {}", if self.audio.muted { "Off" } else { "On" }));
+        ctx.print_centered(13, format!("Best: {}", self.high_scores.best()));
+
+        if let Some(key) = ctx.key { // run block if key is pressed
+
+            match key { // match key pressed to an action
+                VirtualKeyCode::P => self.restart(), // resets state and changes mode to Playing
+                VirtualKeyCode::Q => ctx.quitting = true, // instruct bracket-lib to terminate program
+                VirtualKeyCode::M => self.audio.toggle_mute(), // mute toggle for headless/CI runs
+                _ => {} // all other keys do nothing
+            }
+
+        }
+    }
+
+    fn dead(&mut self, ctx: &mut BTerm){
+        ctx.cls();
+        ctx.print_centered(5, "You are dead!");
+        ctx.print_centered(6, format!("You earned {} points", self.score));
+
+        ctx.print_centered(8, "High Scores");
+        for (rank, score) in self.high_scores.scores.iter().enumerate() {
+            ctx.print_centered(9 + rank as i32, format!("{}. {}", rank + 1, score));
+        }
+
+        ctx.print_centered(15, "(P) Play Again");
+        ctx.print_centered(16, "(Q) Quit Game");
+
+        if let Some(key) = ctx.key {
+
+            match key {
+                VirtualKeyCode::P => self.restart(),
+                VirtualKeyCode::Q => ctx.quitting = true, // terminate program
+                _ => {} // all other keys do nothing
+            }
+        }
+    }
+
+    fn paused(&mut self, ctx: &mut BTerm){
+        // Do not clear the screen - leave the player/obstacle exactly as they were
+        // when the pause key was pressed so the frozen frame stays on screen.
+        self.player.render(ctx);
+        for obstacle in self.obstacles.iter_mut() {
+            obstacle.render(ctx, self.player.x);
+        }
+
+        ctx.print(0, 0, "Press SPACE to flap.");
+        ctx.print(0, 1, format!("Score: {}", self.score));
+        ctx.print_centered(12, "PAUSED -- press P to resume");
+
+        self.advance_paused(ctx.key);
+    }
+
+    // The logic half of `paused`, split out from rendering so it can be
+    // exercised in tests without a real `BTerm`: resume play exactly where
+    // it left off, no state touched while paused.
+    fn advance_paused(&mut self, key: Option<VirtualKeyCode>) {
+        if let Some(VirtualKeyCode::P) = key {
+            self.mode = GameMode::Playing;
+        }
+    }
+
+    fn play(&mut self, ctx: &mut BTerm){
+        // Logic for Play
+        ctx.cls_bg(NAVY); // clear screen and change background color
+
+        self.advance_play(ctx.key, ctx.frame_time_ms);
+
+        // render the updated players state
+        self.player.render(ctx);
+
+        ctx.print(0, 0, "Press SPACE to flap.");
+        ctx.print(0, 1, format!("Score: {}", self.score));
+        ctx.print(0, 2, "Press P to pause.");
+
+        for obstacle in self.obstacles.iter_mut() {
+            obstacle.render(ctx, self.player.x);
+        }
+    }
+
+    // The logic half of `play`, split out from rendering so the physics
+    // step, pause toggle, and obstacle queue's spawn/drop/score bookkeeping
+    // can be exercised in tests without a real `BTerm`.
+    fn advance_play(&mut self, key: Option<VirtualKeyCode>, frame_time_ms: f32) {
+        // Run physics simulation every tick, scaled against frame_time_ms
+        // (time elapsed since tick was last called) so motion is smooth and
+        // frame-rate independent instead of being gated behind a fixed step.
+        self.player.update(frame_time_ms, &self.physics);
+
+        // if space bar is pressed, flap wings - decreases y
+        if let Some(VirtualKeyCode::Space) = key {
+            self.player.flap(&self.physics);
+            self.audio.play_flap();
+        }
+
+        // if P is pressed, freeze the simulation until resumed
+        if let Some(VirtualKeyCode::P) = key {
+            self.mode = GameMode::Paused;
+        }
+
+        // spawn the next obstacle once the furthest-right one is within the
+        // spacing threshold of the player, so several walls are staggered on
+        // screen at once instead of one at a time
+        let next_obstacle = self.obstacles.back().and_then(|furthest| {
+            if furthest.x - self.player.x < SCREEN_WIDTH / 2 {
+                Some(furthest.spawn_after(SCREEN_WIDTH / 2, self.score))
+            } else {
+                None
+            }
+        });
+        if let Some(obstacle) = next_obstacle {
+            self.obstacles.push_back(obstacle);
+        }
+
+        // score and drop every obstacle the player has fully passed - its
+        // screen_x has scrolled off the left edge at that point
+        while let Some(front) = self.obstacles.front() {
+            if self.player.x > front.x {
+                self.score += 1;
+                self.audio.play_point();
+                self.obstacles.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        // if we have fallen off bottom of screen or hit any obstacle
+        let hit_obstacle = self.obstacles.iter().any(|obstacle| obstacle.collides(&self.player));
+        if (self.player.y as i32) > SCREEN_HEIGHT || hit_obstacle {
+            // transition to End State
+            self.audio.play_crash();
+            self.high_scores.try_insert(self.score);
+            self.high_scores.save();
+            self.mode = GameMode::End;
+        }
+    }
+
+    // Advances the game by one frame, dispatching on the current mode. Kept
+    // separate from the `GameState::tick` impl below so the core step logic
+    // has a narrow public entry point that other `GameState`
+    // implementations can call into directly.
+    pub fn step(&mut self, ctx: &mut BTerm) {
+        match self.mode {
+            GameMode::Menu => self.main_menu(ctx),
+            GameMode::End => self.dead(ctx),
+            GameMode::Playing => self.play(ctx),
+            GameMode::Paused => self.paused(ctx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod state_tests {
+    use std::collections::VecDeque;
+
+    use bracket_lib::prelude::VirtualKeyCode;
+
+    use super::{GameMode, State};
+    use crate::obstacle::Obstacle;
+
+    #[test]
+    fn pause_freezes_score_and_obstacles_then_resumes_exactly() {
+        let mut state = State::new();
+        state.mode = GameMode::Playing;
+        state.score = 3;
+        let obstacles_before = state.obstacles.len();
+
+        // press P during Playing: freezes the simulation
+        state.advance_play(Some(VirtualKeyCode::P), 0.0);
+        assert!(matches!(state.mode, GameMode::Paused));
+
+        // while paused, no key presses touch score/obstacles
+        state.advance_paused(None);
+        assert_eq!(state.score, 3);
+        assert_eq!(state.obstacles.len(), obstacles_before);
+        assert!(matches!(state.mode, GameMode::Paused));
+
+        // press P again: resumes exactly where it left off
+        state.advance_paused(Some(VirtualKeyCode::P));
+        assert!(matches!(state.mode, GameMode::Playing));
+        assert_eq!(state.score, 3);
+        assert_eq!(state.obstacles.len(), obstacles_before);
+    }
+
+    #[test]
+    fn play_spawns_next_obstacle_once_within_spacing_threshold() {
+        let mut state = State::new();
+        state.mode = GameMode::Playing;
+        // one obstacle, just ahead of the player - well within the threshold
+        state.obstacles = VecDeque::from([Obstacle::new(state.player.x + 1, state.score)]);
+        let count_before = state.obstacles.len();
+
+        state.advance_play(None, 0.0);
+
+        assert_eq!(state.obstacles.len(), count_before + 1);
+    }
+
+    #[test]
+    fn play_drops_passed_obstacles_and_increments_score() {
+        let mut state = State::new();
+        state.mode = GameMode::Playing;
+        // one obstacle already behind the player - fully passed
+        state.obstacles = VecDeque::from([Obstacle::new(state.player.x - 1, state.score)]);
+        let score_before = state.score;
+
+        state.advance_play(None, 0.0);
+
+        assert_eq!(state.score, score_before + 1);
+        assert!(state.obstacles.iter().all(|obstacle| obstacle.x >= state.player.x));
+    }
+}
+
+// State now implements the trait / interface for GameState
+impl GameState for State {
+    fn tick(&mut self, ctx: &mut BTerm) {
+        // &mut self allows tick function to access and change your State instance
+        // ctx provides a window into the currently running bracket-terminal
+        // ctx provides functions for interacting with the game display
+        self.step(ctx);
+    }
+}
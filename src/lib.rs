@@ -0,0 +1,16 @@
+pub mod game;
+pub mod obstacle;
+pub mod player;
+
+pub use game::State;
+
+// Constants - known at compile time, shared across the player/obstacle/game modules.
+// Public so custom obstacle generators or alternate players can build against
+// the same world-space bounds and default physics tuning `State` uses.
+pub const SCREEN_WIDTH : i32 = 80;
+pub const SCREEN_HEIGHT : i32 = 50;
+pub const FRAME_DURATION :  f32 = 75.0;  // in milliseconds
+pub const TERMINAL_VELOCITY: f32 = 2.0;
+pub const GRAVITY: f32 = 0.2;
+pub const FLAP_STRENGTH: f32 = 1.0;
+pub(crate) const ASSETS_DIR: &str = "resources"; // directory clips are loaded from
@@ -0,0 +1,109 @@
+use bracket_lib::prelude::*;
+
+use crate::player::Player;
+use crate::SCREEN_HEIGHT;
+
+// Axis-aligned bounding box in world space, used for collision checks.
+struct Rect {
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+}
+
+impl Rect {
+    fn new(x0: i32, y0: i32, x1: i32, y1: i32) -> Self {
+        Self { x0, y0, x1, y1 }
+    }
+
+    fn overlaps(&self, other: &Rect) -> bool {
+        self.x0 < other.x1 && self.x1 > other.x0 && self.y0 < other.y1 && self.y1 > other.y0
+    }
+}
+
+pub struct Obstacle {
+    pub x: i32,
+    gap_y: i32,
+    size: i32
+}
+
+impl Obstacle {
+    pub fn new(x: i32, score: i32) -> Self {
+        let mut random = RandomNumberGenerator::new();
+        Self {
+            x,
+            gap_y: random.range(10, 40),
+            size: i32::max(2, 20 - score)
+        }
+    }
+
+    // Spawns the next obstacle `spacing` world units past this one, so
+    // callers never have to poke at obstacle internals to stagger walls.
+    pub fn spawn_after(&self, spacing: i32, score: i32) -> Self {
+        Self::new(self.x + spacing, score)
+    }
+
+    pub(crate) fn render(&mut self, ctx: &mut BTerm, player_x: i32){
+
+        let screen_x = self.x - player_x;
+        let half_size = self.size / 2;
+
+        // Draw top half of the obstacle
+        for y in 0..self.gap_y - half_size {
+            ctx.set(screen_x, y, RED, BLACK, to_cp437('|'));
+        }
+
+        // Draw bottom half of the obstacle
+        for y in self.gap_y + half_size..SCREEN_HEIGHT {
+            ctx.set(screen_x, y, RED, BLACK, to_cp437('|'));
+        }
+    }
+
+    // Treats the player as a 1x1 box and the obstacle as a top/bottom pillar
+    // pair at its x column, and reports whether the player's box overlaps
+    // either pillar. Only the player's current cell is tested, so a frame
+    // that moves x past the column without landing on it is not detected
+    // here (`Player::update` limits each frame to one unit of x). Built on
+    // `Rect::overlaps` so wider sprites/obstacles in future will still
+    // collide correctly.
+    pub fn collides(&self, player: &Player) -> bool {
+        let half_size = self.size / 2;
+        let player_y = player.y as i32;
+        let player_rect = Rect::new(player.x, player_y, player.x + 1, player_y + 1);
+
+        let top_pillar = Rect::new(self.x, 0, self.x + 1, self.gap_y - half_size);
+        let bottom_pillar = Rect::new(self.x, self.gap_y + half_size, self.x + 1, SCREEN_HEIGHT);
+
+        player_rect.overlaps(&top_pillar) || player_rect.overlaps(&bottom_pillar)
+    }
+}
+
+#[cfg(test)]
+mod rect_tests {
+    use super::Rect;
+
+    #[test]
+    fn overlapping_rects_collide() {
+        let a = Rect::new(0, 0, 2, 2);
+        let b = Rect::new(1, 1, 3, 3);
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+    }
+
+    #[test]
+    fn touching_edges_do_not_collide() {
+        // b starts exactly where a ends on the x axis - edges touch but
+        // don't overlap, matching the strict `<`/`>` comparison in `overlaps`
+        let a = Rect::new(0, 0, 2, 2);
+        let b = Rect::new(2, 0, 4, 2);
+        assert!(!a.overlaps(&b));
+        assert!(!b.overlaps(&a));
+    }
+
+    #[test]
+    fn disjoint_rects_do_not_collide() {
+        let a = Rect::new(0, 0, 1, 1);
+        let b = Rect::new(5, 5, 6, 6);
+        assert!(!a.overlaps(&b));
+    }
+}
@@ -0,0 +1,175 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use bracket_lib::prelude::*;
+
+use crate::{FLAP_STRENGTH, FRAME_DURATION, GRAVITY, TERMINAL_VELOCITY};
+
+// Tunable physics constants, owned by `State` so difficulty can be tuned
+// independently of the frame loop. Units are "per FRAME_DURATION", the
+// interval the constants above were originally balanced around; values are
+// scaled against the actual elapsed time in `Player::update`.
+pub struct PhysicsConfig {
+    pub(crate) gravity: f32,
+    pub(crate) terminal_velocity: f32,
+    pub(crate) flap_strength: f32,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            gravity: GRAVITY,
+            terminal_velocity: TERMINAL_VELOCITY,
+            flap_strength: FLAP_STRENGTH,
+        }
+    }
+}
+
+// Small 2D vector used for the player's velocity, so vertical motion is
+// integrated the same way as the horizontal component.
+#[derive(Clone, Copy, Default)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+impl Vec2 {
+    fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+// The Dragons current state
+pub struct Player {
+    pub x: i32,     // world space location in terminal characters, represents progress through level
+    x_progress: f32, // fractional world-space x, rounded down into `x` each frame
+    pub y: f32,     // vertical position in screen space
+    velocity: Vec2,   // players velocity: x is constant forward speed, y is vertical speed
+}
+
+impl Player {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self {
+            x,
+            x_progress: x as f32,
+            y: y as f32,
+            velocity: Vec2::new(1.0, 0.0),
+        }
+    }
+
+    pub(crate) fn render(&mut self, ctx: &mut BTerm){ // allow to mutate instance and pass in context for game engine
+        // sets a single character on the screen
+        // this is screen space, world space is defined by values in player.x and player.y
+        ctx.set(0, self.y as i32, YELLOW, BLACK, to_cp437('@'));
+    }
+
+    pub fn update(&mut self, frame_time_ms: f32, physics: &PhysicsConfig){
+        // scale this frame's motion against the interval the physics constants
+        // were tuned for, so the game feels identical at any frame rate.
+        // Clamped to one `FRAME_DURATION` worth of motion so a frame hitch
+        // (e.g. the OS stalling the process) can't advance `x` by more than
+        // one world unit in a single tick - otherwise the 1-unit-wide
+        // obstacle pillars in `Obstacle::collides` could be stepped clean
+        // over without ever overlapping the player's rect.
+        let dt = (frame_time_ms / FRAME_DURATION).min(1.0);
+
+        // NOTE: +ve velocity is in downwards screen direction, i.e. +ve Y co-ordinate direction.
+        // Clamp the result, not only gate the increment on the old value,
+        // so a large dt (or float rounding right at the cutoff) can't overshoot
+        // terminal velocity in a single step.
+        if self.velocity.y < physics.terminal_velocity {
+            self.velocity.y = (self.velocity.y + physics.gravity * dt).min(physics.terminal_velocity);
+        }
+
+        self.y += self.velocity.y * dt;
+
+        self.x_progress += self.velocity.x * dt; // move horizontally across the screen
+        self.x = self.x_progress as i32;
+
+        if self.y < 0.0 { // zero is the 'top' of the screen
+            self.y = 0.0;  // y can never be less than zero
+        }
+
+    }
+
+    pub fn flap(&mut self, physics: &PhysicsConfig){
+        self.velocity.y = -physics.flap_strength; // velocity in upwards direction
+    }
+}
+
+#[cfg(test)]
+mod player_tests {
+    use super::{Player, PhysicsConfig};
+    use crate::FRAME_DURATION;
+
+    #[test]
+    fn update_scales_motion_by_dt() {
+        let physics = PhysicsConfig::default();
+        let mut half_step = Player::new(0, 25);
+        half_step.update(FRAME_DURATION / 2.0, &physics);
+
+        let mut full_step = Player::new(0, 25);
+        full_step.update(FRAME_DURATION, &physics);
+
+        // a half-duration frame should move the player less far vertically
+        // than a full-duration frame (both the gravity increment and the
+        // displacement scale with dt, so it is about a quarter as far)
+        assert!(half_step.y < full_step.y);
+    }
+
+    #[test]
+    fn update_clamps_dt_to_one_frame_duration() {
+        // a stalled frame far longer than FRAME_DURATION must not advance `x`
+        // by more than one world unit, or the 1-unit-wide obstacle pillars
+        // could be stepped clean over without ever overlapping the player
+        let physics = PhysicsConfig::default();
+        let mut player = Player::new(0, 25);
+        player.update(FRAME_DURATION * 10.0, &physics);
+        assert_eq!(player.x, 1);
+    }
+
+    #[test]
+    fn update_clamps_velocity_to_terminal_velocity() {
+        let physics = PhysicsConfig::default();
+        let mut player = Player::new(0, 25);
+        for _ in 0..1000 {
+            player.update(FRAME_DURATION, &physics);
+        }
+        assert!(player.velocity.y <= physics.terminal_velocity);
+    }
+
+    #[test]
+    fn update_clamps_y_to_the_top_of_the_screen() {
+        let physics = PhysicsConfig::default();
+        let mut player = Player::new(0, 25);
+        player.flap(&physics);
+        // flapping for long enough would otherwise push y below zero
+        for _ in 0..1000 {
+            player.update(FRAME_DURATION, &physics);
+            player.flap(&physics);
+        }
+        assert!(player.y >= 0.0);
+    }
+}